@@ -1,69 +1,557 @@
 use std::collections::HashMap;
 use std::fmt;
 
+/// The kind of failure a [`ParseError`] represents, independent of where in
+/// the document it happened. Mirrors the classic Rust JSON library's
+/// `ErrorCode` enum.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum ErrorCode {
+    InvalidSyntax,
+    KeyMustBeAString,
+    ExpectedColon,
+    TrailingCharacter,
+    EOFWhileParsingString,
+    InvalidNumber,
+    InvalidEscape,
+}
+
+impl ErrorCode {
+    fn message(self) -> &'static str {
+        match self {
+            ErrorCode::InvalidSyntax => "invalid syntax",
+            ErrorCode::KeyMustBeAString => "key must be a string",
+            ErrorCode::ExpectedColon => "expected ':'",
+            ErrorCode::TrailingCharacter => "trailing character",
+            ErrorCode::EOFWhileParsingString => "EOF while parsing string",
+            ErrorCode::InvalidNumber => "invalid number",
+            ErrorCode::InvalidEscape => "invalid escape",
+        }
+    }
+}
+
+/// A parse failure located at a 1-based line/column in the input, e.g.
+/// `expected ':' at line 3 column 12`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct ParseError {
+    code: ErrorCode,
+    line: usize,
+    column: usize,
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} at line {} column {}",
+            self.code.message(),
+            self.line,
+            self.column
+        )
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// Computes the 1-based (line, column) of the given byte `offset` into
+/// `input`.
+fn line_col(input: &str, offset: usize) -> (usize, usize) {
+    let offset = offset.min(input.len());
+    let mut line = 1;
+    let mut column = 1;
+
+    for c in input[..offset].chars() {
+        if c == '\n' {
+            line += 1;
+            column = 1;
+        } else {
+            column += 1;
+        }
+    }
+
+    (line, column)
+}
+
+/// Decodes JSON string escape sequences (`\" \\ \/ \b \f \n \r \t` and
+/// `\uXXXX`, including UTF-16 surrogate pairs) in the unquoted body of a
+/// JSON string literal. On failure, returns the byte offset *within
+/// `input`* of the offending escape so the caller can map it back to an
+/// absolute position in the document.
+fn unescape(input: &str) -> Result<String, (ErrorCode, usize)> {
+    let mut out = String::with_capacity(input.len());
+    let mut chars = input.char_indices().peekable();
+
+    while let Some((idx, c)) = chars.next() {
+        if c != '\\' {
+            out.push(c);
+            continue;
+        }
+
+        match chars.next() {
+            Some((_, '"')) => out.push('"'),
+            Some((_, '\\')) => out.push('\\'),
+            Some((_, '/')) => out.push('/'),
+            Some((_, 'b')) => out.push('\u{8}'),
+            Some((_, 'f')) => out.push('\u{c}'),
+            Some((_, 'n')) => out.push('\n'),
+            Some((_, 'r')) => out.push('\r'),
+            Some((_, 't')) => out.push('\t'),
+            Some((_, 'u')) => {
+                let high = read_hex4(&mut chars).map_err(|_| (ErrorCode::InvalidEscape, idx))?;
+
+                if (0xD800..0xDC00).contains(&high) {
+                    match (chars.next(), chars.next()) {
+                        (Some((_, '\\')), Some((_, 'u'))) => {
+                            let low = read_hex4(&mut chars)
+                                .map_err(|_| (ErrorCode::InvalidEscape, idx))?;
+                            if !(0xDC00..0xE000).contains(&low) {
+                                return Err((ErrorCode::InvalidEscape, idx));
+                            }
+                            let code =
+                                ((high as u32 - 0xD800) << 10) + (low as u32 - 0xDC00) + 0x10000;
+                            let c = char::from_u32(code)
+                                .ok_or((ErrorCode::InvalidEscape, idx))?;
+                            out.push(c);
+                        }
+                        _ => return Err((ErrorCode::InvalidEscape, idx)),
+                    }
+                } else if (0xDC00..0xE000).contains(&high) {
+                    return Err((ErrorCode::InvalidEscape, idx));
+                } else {
+                    let c = char::from_u32(high as u32).ok_or((ErrorCode::InvalidEscape, idx))?;
+                    out.push(c);
+                }
+            }
+            _ => return Err((ErrorCode::InvalidEscape, idx)),
+        }
+    }
+
+    Ok(out)
+}
+
+/// Reads exactly four hex digits off `chars` and parses them as a `u16`.
+fn read_hex4(chars: &mut std::iter::Peekable<std::str::CharIndices>) -> Result<u16, ()> {
+    let mut hex = String::with_capacity(4);
+    for _ in 0..4 {
+        match chars.next() {
+            Some((_, c)) if c.is_ascii_hexdigit() => hex.push(c),
+            _ => return Err(()),
+        }
+    }
+    u16::from_str_radix(&hex, 16).map_err(|_| ())
+}
+
+/// Escapes a string for JSON output, the inverse of [`unescape`].
+fn escape(input: &str) -> String {
+    let mut out = String::with_capacity(input.len());
+
+    for c in input.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\u{8}' => out.push_str("\\b"),
+            '\u{c}' => out.push_str("\\f"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if (c as u32) < 0x20 => out.push_str(&format!("\\u{:04x}", c as u32)),
+            c => out.push(c),
+        }
+    }
+
+    out
+}
+
+/// One step of a streaming JSON parse: the start/end of a container, a
+/// scalar value, or a terminal parse error. Mirrors the shape of the old
+/// `rustc_serialize::json::JsonEvent` API so a caller can consume a large
+/// document without materializing the whole `JsonValue` tree.
 #[derive(Debug, PartialEq)]
-enum JsonValue {
-    String(String),
-    Number(f64),
-    Boolean(bool),
-    Object(HashMap<String, JsonValue>),
-    Null,
+enum JsonEvent {
+    ObjectStart,
+    ObjectEnd,
+    ArrayStart,
+    ArrayEnd,
+    StringValue(String),
+    I64Value(i64),
+    U64Value(u64),
+    F64Value(f64),
+    BooleanValue(bool),
+    NullValue,
+    Error(ParseError),
 }
 
-impl JsonValue {
-    fn parse(input: &str) -> Result<JsonValue, String> {
-        let input = input.trim();
+/// One step of the path from the document root down to the value the
+/// [`Parser`] is currently positioned at.
+#[derive(Debug, Clone, PartialEq)]
+enum StackElement {
+    Index(usize),
+    Key(String),
+}
+
+/// An open container the parser is currently inside, tracking whether the
+/// next token must *not* be preceded by a comma (i.e. it's the first entry).
+#[derive(Debug, Clone, Copy)]
+enum Container {
+    Object { first: bool },
+    Array { first: bool },
+}
 
-        match input.chars().next() {
-            Some('"') => Self::parse_string(input),
-            Some('{') => Self::parse_object(&input[1..input.len() - 1]),
-            Some(_) if input == "true" || input == "false" => Self::parse_boolean(input),
-            Some(_) if input == "null" => Ok(JsonValue::Null),
-            Some(_) => Self::parse_number(input),
-            None => Err("Empty input".to_string()),
+/// A streaming pull-parser over a JSON document. Each call to `next()`
+/// yields one [`JsonEvent`] instead of materializing the whole tree, so a
+/// caller can inspect [`Parser::stack`] to filter for a field nested deep in
+/// a document without building the full map. `JsonValue::parse` is built on
+/// top of this.
+struct Parser<'a> {
+    input: &'a str,
+    chars: std::iter::Peekable<std::str::CharIndices<'a>>,
+    pos: usize,
+    stack: Vec<StackElement>,
+    containers: Vec<Container>,
+    started: bool,
+    done: bool,
+}
+
+impl<'a> Parser<'a> {
+    fn new(input: &'a str) -> Self {
+        Parser {
+            input,
+            chars: input.char_indices().peekable(),
+            pos: 0,
+            stack: Vec::new(),
+            containers: Vec::new(),
+            started: false,
+            done: false,
         }
     }
 
-    fn parse_string(input: &str) -> Result<JsonValue, String> {
-        if input.starts_with('"') && input.ends_with('"') {
-            Ok(JsonValue::String(input[1..input.len() - 1].to_string()))
-        } else {
-            Err("Invalid string format".to_string())
+    /// The current nesting path, e.g. `[Key("courses"), Index(1)]` while
+    /// positioned inside `courses[1]`.
+    fn stack(&self) -> &[StackElement] {
+        &self.stack
+    }
+
+    fn peek_char(&mut self) -> Option<char> {
+        self.chars.peek().map(|&(_, c)| c)
+    }
+
+    fn bump(&mut self) -> Option<char> {
+        let (idx, c) = self.chars.next()?;
+        self.pos = idx + c.len_utf8();
+        Some(c)
+    }
+
+    fn skip_whitespace(&mut self) {
+        while let Some(c) = self.peek_char() {
+            if c.is_whitespace() {
+                self.bump();
+            } else {
+                break;
+            }
         }
     }
 
-    fn parse_number(input: &str) -> Result<JsonValue, String> {
-        input
-            .parse::<f64>()
-            .map(JsonValue::Number)
-            .map_err(|_| "Invalid number format".to_string())
+    fn error_at(&mut self, code: ErrorCode, offset: usize) -> JsonEvent {
+        self.done = true;
+        let (line, column) = line_col(self.input, offset);
+        JsonEvent::Error(ParseError { code, line, column })
     }
 
-    fn parse_boolean(input: &str) -> Result<JsonValue, String> {
-        match input {
-            "true" => Ok(JsonValue::Boolean(true)),
-            "false" => Ok(JsonValue::Boolean(false)),
-            _ => Err("Invalid boolean format".to_string()),
+    fn mark_container_progressed(&mut self) {
+        match self.containers.last_mut() {
+            Some(Container::Object { first }) => *first = false,
+            Some(Container::Array { first }) => *first = false,
+            None => {}
         }
     }
 
-    fn parse_object(input: &str) -> Result<JsonValue, String> {
-        let mut map = HashMap::new();
-        let pairs = input
-            .split(',')
-            .map(|pair| pair.splitn(2, ':').map(str::trim).collect::<Vec<&str>>());
-
-        for pair in pairs {
-            if let [key, value] = pair.as_slice() {
-                let key = key.trim_matches('"');
-                let value = JsonValue::parse(value)?;
-                map.insert(key.to_string(), value);
+    fn parse_string_token(&mut self) -> Result<String, JsonEvent> {
+        let quote_offset = self.pos;
+        self.bump(); // consume opening quote
+        let body_start = self.pos;
+        let mut raw = String::new();
+
+        loop {
+            match self.bump() {
+                Some('"') => break,
+                Some('\\') => {
+                    raw.push('\\');
+                    match self.bump() {
+                        Some(c) => raw.push(c),
+                        None => {
+                            return Err(self.error_at(ErrorCode::EOFWhileParsingString, quote_offset))
+                        }
+                    }
+                }
+                Some(c) => raw.push(c),
+                None => return Err(self.error_at(ErrorCode::EOFWhileParsingString, quote_offset)),
+            }
+        }
+
+        unescape(&raw).map_err(|(code, local_offset)| self.error_at(code, body_start + local_offset))
+    }
+
+    fn parse_number_token(&mut self) -> JsonEvent {
+        let start = self.pos;
+        let mut raw = String::new();
+        if self.peek_char() == Some('-') {
+            raw.push('-');
+            self.bump();
+        }
+        while let Some(c) = self.peek_char() {
+            if c.is_ascii_digit() || matches!(c, '.' | 'e' | 'E' | '+') {
+                raw.push(c);
+                self.bump();
             } else {
-                return Err("Invalid object entry".to_string());
+                break;
+            }
+        }
+
+        let is_float = raw.contains(['.', 'e', 'E']);
+        if !is_float {
+            if let Ok(i) = raw.parse::<i64>() {
+                return JsonEvent::I64Value(i);
+            }
+            if let Ok(u) = raw.parse::<u64>() {
+                return JsonEvent::U64Value(u);
+            }
+        }
+
+        match raw.parse::<f64>() {
+            Ok(f) if f.is_finite() => JsonEvent::F64Value(f),
+            _ => self.error_at(ErrorCode::InvalidNumber, start),
+        }
+    }
+
+    fn expect_literal_event(&mut self, literal: &str, event: JsonEvent) -> JsonEvent {
+        let start = self.pos;
+        for expected in literal.chars() {
+            if self.bump() != Some(expected) {
+                return self.error_at(ErrorCode::InvalidSyntax, start);
+            }
+        }
+        event
+    }
+
+    /// Reads the next value token, pushing a new [`Container`] frame when it
+    /// opens an object/array rather than reading the value itself.
+    fn read_value_start(&mut self) -> JsonEvent {
+        self.skip_whitespace();
+        let start = self.pos;
+
+        match self.peek_char() {
+            Some('{') => {
+                self.bump();
+                self.containers.push(Container::Object { first: true });
+                self.stack.push(StackElement::Key(String::new()));
+                JsonEvent::ObjectStart
+            }
+            Some('[') => {
+                self.bump();
+                self.containers.push(Container::Array { first: true });
+                self.stack.push(StackElement::Index(0));
+                JsonEvent::ArrayStart
             }
+            Some('"') => match self.parse_string_token() {
+                Ok(s) => JsonEvent::StringValue(s),
+                Err(e) => e,
+            },
+            Some('t') => self.expect_literal_event("true", JsonEvent::BooleanValue(true)),
+            Some('f') => self.expect_literal_event("false", JsonEvent::BooleanValue(false)),
+            Some('n') => self.expect_literal_event("null", JsonEvent::NullValue),
+            Some(c) if c == '-' || c.is_ascii_digit() => self.parse_number_token(),
+            Some(_) => self.error_at(ErrorCode::InvalidSyntax, start),
+            None => self.error_at(ErrorCode::InvalidSyntax, start),
+        }
+    }
+
+    /// Reads a `"key": value` entry of an object, parked on the stack as
+    /// `StackElement::Key`, then returns the event for its value.
+    fn read_object_entry(&mut self) -> JsonEvent {
+        let start = self.pos;
+        if self.peek_char() != Some('"') {
+            return self.error_at(ErrorCode::KeyMustBeAString, start);
+        }
+        let key = match self.parse_string_token() {
+            Ok(k) => k,
+            Err(e) => return e,
+        };
+
+        self.skip_whitespace();
+        let colon_pos = self.pos;
+        if self.peek_char() != Some(':') {
+            return self.error_at(ErrorCode::ExpectedColon, colon_pos);
+        }
+        self.bump();
+        self.skip_whitespace();
+
+        if let Some(StackElement::Key(k)) = self.stack.last_mut() {
+            *k = key;
         }
+        self.mark_container_progressed();
+        self.read_value_start()
+    }
+}
 
-        Ok(JsonValue::Object(map))
+impl<'a> Iterator for Parser<'a> {
+    type Item = JsonEvent;
+
+    fn next(&mut self) -> Option<JsonEvent> {
+        if self.done {
+            return None;
+        }
+
+        match self.containers.last().copied() {
+            None => {
+                if self.started {
+                    self.skip_whitespace();
+                    let offset = self.pos;
+                    if self.peek_char().is_some() {
+                        return Some(self.error_at(ErrorCode::TrailingCharacter, offset));
+                    }
+                    self.done = true;
+                    return None;
+                }
+                self.started = true;
+                Some(self.read_value_start())
+            }
+            Some(Container::Object { first }) => {
+                self.skip_whitespace();
+                let offset = self.pos;
+                match self.peek_char() {
+                    Some('}') => {
+                        self.bump();
+                        self.containers.pop();
+                        self.stack.pop();
+                        Some(JsonEvent::ObjectEnd)
+                    }
+                    Some(',') if !first => {
+                        self.bump();
+                        self.skip_whitespace();
+                        Some(self.read_object_entry())
+                    }
+                    None | Some(',') => Some(self.error_at(ErrorCode::InvalidSyntax, offset)),
+                    _ if first => Some(self.read_object_entry()),
+                    Some(_) => Some(self.error_at(ErrorCode::InvalidSyntax, offset)),
+                }
+            }
+            Some(Container::Array { first }) => {
+                self.skip_whitespace();
+                let offset = self.pos;
+                match self.peek_char() {
+                    Some(']') => {
+                        self.bump();
+                        self.containers.pop();
+                        self.stack.pop();
+                        Some(JsonEvent::ArrayEnd)
+                    }
+                    Some(',') if !first => {
+                        self.bump();
+                        self.skip_whitespace();
+                        if let Some(StackElement::Index(i)) = self.stack.last_mut() {
+                            *i += 1;
+                        }
+                        self.mark_container_progressed();
+                        Some(self.read_value_start())
+                    }
+                    None | Some(',') => Some(self.error_at(ErrorCode::InvalidSyntax, offset)),
+                    _ if first => {
+                        self.mark_container_progressed();
+                        Some(self.read_value_start())
+                    }
+                    Some(_) => Some(self.error_at(ErrorCode::InvalidSyntax, offset)),
+                }
+            }
+        }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+enum JsonValue {
+    String(String),
+    I64(i64),
+    U64(u64),
+    F64(f64),
+    Boolean(bool),
+    Object(HashMap<String, JsonValue>),
+    Array(Vec<JsonValue>),
+    Null,
+}
+
+impl JsonValue {
+    fn parse(input: &str) -> Result<JsonValue, ParseError> {
+        if input.trim().is_empty() {
+            return Err(ParseError {
+                code: ErrorCode::InvalidSyntax,
+                line: 1,
+                column: 1,
+            });
+        }
+
+        let mut parser = Parser::new(input);
+        let first = match parser.next() {
+            Some(JsonEvent::Error(e)) => return Err(e),
+            Some(event) => event,
+            None => unreachable!("a non-empty input always yields a first event"),
+        };
+
+        let value = Self::from_event(&mut parser, first)?;
+
+        match parser.next() {
+            None => Ok(value),
+            Some(JsonEvent::Error(e)) => Err(e),
+            Some(_) => unreachable!("Parser::next only emits Error events once trailing"),
+        }
+    }
+
+    /// Reconstructs a `JsonValue` by driving `parser` from `event` onward,
+    /// recursing into nested containers until their matching `*End` event.
+    fn from_event(parser: &mut Parser, event: JsonEvent) -> Result<JsonValue, ParseError> {
+        match event {
+            JsonEvent::StringValue(s) => Ok(JsonValue::String(s)),
+            JsonEvent::I64Value(n) => Ok(JsonValue::I64(n)),
+            JsonEvent::U64Value(n) => Ok(JsonValue::U64(n)),
+            JsonEvent::F64Value(n) => Ok(JsonValue::F64(n)),
+            JsonEvent::BooleanValue(b) => Ok(JsonValue::Boolean(b)),
+            JsonEvent::NullValue => Ok(JsonValue::Null),
+            JsonEvent::Error(e) => Err(e),
+            JsonEvent::ObjectStart => {
+                // This object's own `Key` frame was just pushed onto the
+                // stack, so it sits at this fixed depth for the rest of the
+                // loop; nested values push/pop frames above it.
+                let depth = parser.stack().len() - 1;
+                let mut map = HashMap::new();
+                loop {
+                    match parser.next() {
+                        Some(JsonEvent::ObjectEnd) => break,
+                        Some(JsonEvent::Error(e)) => return Err(e),
+                        Some(event) => {
+                            let key = match parser.stack().get(depth) {
+                                Some(StackElement::Key(k)) => k.clone(),
+                                _ => unreachable!("an object frame's stack slot is always a Key"),
+                            };
+                            map.insert(key, Self::from_event(parser, event)?);
+                        }
+                        None => unreachable!("the streaming parser never ends mid-object"),
+                    }
+                }
+                Ok(JsonValue::Object(map))
+            }
+            JsonEvent::ArrayStart => {
+                let mut elements = Vec::new();
+                loop {
+                    match parser.next() {
+                        Some(JsonEvent::ArrayEnd) => break,
+                        Some(JsonEvent::Error(e)) => return Err(e),
+                        Some(event) => elements.push(Self::from_event(parser, event)?),
+                        None => unreachable!("the streaming parser never ends mid-array"),
+                    }
+                }
+                Ok(JsonValue::Array(elements))
+            }
+            JsonEvent::ObjectEnd | JsonEvent::ArrayEnd => {
+                unreachable!("*End events are only consumed by their container's loop")
+            }
+        }
     }
 }
 
@@ -71,10 +559,16 @@ impl JsonValue {
 impl fmt::Display for JsonValue {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
-            JsonValue::String(s) => write!(f, "\"{}\"", s),
-            JsonValue::Number(n) => write!(f, "{}", n),
+            JsonValue::String(s) => write!(f, "\"{}\"", escape(s)),
+            JsonValue::I64(n) => write!(f, "{}", n),
+            JsonValue::U64(n) => write!(f, "{}", n),
+            JsonValue::F64(n) => write!(f, "{}", n),
             JsonValue::Boolean(b) => write!(f, "{}", b),
             JsonValue::Null => write!(f, "null"),
+            JsonValue::Array(items) => {
+                let entries: Vec<String> = items.iter().map(|v| v.to_string()).collect();
+                write!(f, "[{}]", entries.join(","))
+            }
             JsonValue::Object(map) => {
                 let mut entries: Vec<String> = map
                     .iter()
@@ -87,6 +581,224 @@ impl fmt::Display for JsonValue {
     }
 }
 
+impl JsonValue {
+    /// Renders this value as pretty-printed JSON, indenting nested arrays
+    /// and objects by `indent` spaces per level. Object entries are sorted by
+    /// key, so the output is deterministic. `Display` sorts whole
+    /// `"key":value` entries instead, so the two orders can differ when one
+    /// key is a prefix of another (e.g. `a` and `a!`).
+    pub fn to_string_pretty(&self, indent: usize) -> String {
+        let mut out = String::new();
+        self.write_pretty(&mut out, indent, 0);
+        out
+    }
+
+    fn write_pretty(&self, out: &mut String, indent: usize, level: usize) {
+        match self {
+            JsonValue::Array(items) => {
+                if items.is_empty() {
+                    out.push_str("[]");
+                    return;
+                }
+                out.push_str("[\n");
+                let last = items.len() - 1;
+                for (i, item) in items.iter().enumerate() {
+                    out.push_str(&" ".repeat(indent * (level + 1)));
+                    item.write_pretty(out, indent, level + 1);
+                    if i != last {
+                        out.push(',');
+                    }
+                    out.push('\n');
+                }
+                out.push_str(&" ".repeat(indent * level));
+                out.push(']');
+            }
+            JsonValue::Object(map) => {
+                if map.is_empty() {
+                    out.push_str("{}");
+                    return;
+                }
+                let mut keys: Vec<&String> = map.keys().collect();
+                keys.sort();
+                out.push_str("{\n");
+                let last = keys.len() - 1;
+                for (i, key) in keys.iter().enumerate() {
+                    out.push_str(&" ".repeat(indent * (level + 1)));
+                    out.push_str(&format!("\"{}\": ", key));
+                    map[*key].write_pretty(out, indent, level + 1);
+                    if i != last {
+                        out.push(',');
+                    }
+                    out.push('\n');
+                }
+                out.push_str(&" ".repeat(indent * level));
+                out.push('}');
+            }
+            other => out.push_str(&other.to_string()),
+        }
+    }
+}
+
+/// A failure to decode a `JsonValue` into a typed Rust value. Modeled on the
+/// classic `rustc_serialize::json::DecoderError`.
+#[derive(Debug, Clone, PartialEq)]
+enum DecodeError {
+    /// The shape expected and the JSON actually found, e.g.
+    /// `ExpectedError("Number", "[]")`.
+    ExpectedError(String, String),
+    ParseError(ParseError),
+}
+
+impl DecodeError {
+    fn expected(expected: &str, found: &JsonValue) -> DecodeError {
+        DecodeError::ExpectedError(expected.to_string(), found.to_string())
+    }
+}
+
+impl fmt::Display for DecodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DecodeError::ExpectedError(expected, found) => {
+                write!(f, "expected {}, found {}", expected, found)
+            }
+            DecodeError::ParseError(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+impl std::error::Error for DecodeError {}
+
+impl From<ParseError> for DecodeError {
+    fn from(e: ParseError) -> Self {
+        DecodeError::ParseError(e)
+    }
+}
+
+/// A type that can be pulled out of a `JsonValue` tree. Implement this for a
+/// struct to decode it field-by-field via [`Decoder::read`] instead of
+/// matching on `JsonValue::Object` by hand.
+trait Decodable: Sized {
+    fn decode(value: &JsonValue) -> Result<Self, DecodeError>;
+}
+
+impl Decodable for String {
+    fn decode(value: &JsonValue) -> Result<Self, DecodeError> {
+        match value {
+            JsonValue::String(s) => Ok(s.clone()),
+            other => Err(DecodeError::expected("String", other)),
+        }
+    }
+}
+
+impl Decodable for bool {
+    fn decode(value: &JsonValue) -> Result<Self, DecodeError> {
+        match value {
+            JsonValue::Boolean(b) => Ok(*b),
+            other => Err(DecodeError::expected("Boolean", other)),
+        }
+    }
+}
+
+impl Decodable for f64 {
+    fn decode(value: &JsonValue) -> Result<Self, DecodeError> {
+        match value {
+            JsonValue::F64(n) => Ok(*n),
+            JsonValue::I64(n) => Ok(*n as f64),
+            JsonValue::U64(n) => Ok(*n as f64),
+            other => Err(DecodeError::expected("Number", other)),
+        }
+    }
+}
+
+macro_rules! impl_decodable_int {
+    ($($t:ty),*) => {
+        $(
+            impl Decodable for $t {
+                fn decode(value: &JsonValue) -> Result<Self, DecodeError> {
+                    match value {
+                        JsonValue::I64(n) => {
+                            <$t>::try_from(*n).map_err(|_| DecodeError::expected("Number", value))
+                        }
+                        JsonValue::U64(n) => {
+                            <$t>::try_from(*n).map_err(|_| DecodeError::expected("Number", value))
+                        }
+                        other => Err(DecodeError::expected("Number", other)),
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_decodable_int!(usize, u32, u64, i32, i64);
+
+impl<T: Decodable> Decodable for Option<T> {
+    fn decode(value: &JsonValue) -> Result<Self, DecodeError> {
+        match value {
+            JsonValue::Null => Ok(None),
+            other => T::decode(other).map(Some),
+        }
+    }
+}
+
+impl<T: Decodable> Decodable for Vec<T> {
+    fn decode(value: &JsonValue) -> Result<Self, DecodeError> {
+        match value {
+            JsonValue::Array(items) => items.iter().map(T::decode).collect(),
+            other => Err(DecodeError::expected("Array", other)),
+        }
+    }
+}
+
+/// Walks a parsed `JsonValue`, handing typed fields to a `Decodable`
+/// implementation. A missing field decodes as `JsonValue::Null`, which lets
+/// `Option<T>` fields default to `None` instead of erroring.
+struct Decoder<'a> {
+    value: &'a JsonValue,
+}
+
+impl<'a> Decoder<'a> {
+    const NULL: JsonValue = JsonValue::Null;
+
+    fn new(value: &'a JsonValue) -> Self {
+        Decoder { value }
+    }
+
+    fn read<T: Decodable>(&self, field: &str) -> Result<T, DecodeError> {
+        let value = match self.value {
+            JsonValue::Object(map) => map.get(field).unwrap_or(&Self::NULL),
+            other => return Err(DecodeError::expected("Object", other)),
+        };
+        T::decode(value)
+    }
+}
+
+/// Parses `input` and decodes it as `T` in one step, as
+/// `rustc_serialize::json::decode` used to.
+fn decode<T: Decodable>(input: &str) -> Result<T, DecodeError> {
+    let value = JsonValue::parse(input)?;
+    T::decode(&value)
+}
+
+struct Person {
+    name: String,
+    age: usize,
+    is_student: bool,
+    courses: Option<String>,
+}
+
+impl Decodable for Person {
+    fn decode(value: &JsonValue) -> Result<Self, DecodeError> {
+        let d = Decoder::new(value);
+        Ok(Person {
+            name: d.read("name")?,
+            age: d.read("age")?,
+            is_student: d.read("is_student")?,
+            courses: d.read("courses")?,
+        })
+    }
+}
+
 fn main() {
     let json_str = r#"
     {
@@ -102,9 +814,18 @@ fn main() {
             println!("Parsed JSON: {:#?}", parsed_json);
             let json_string = parsed_json.to_string();
             println!("Stringified JSON: {}", json_string);
+            println!("Pretty JSON:\n{}", parsed_json.to_string_pretty(2));
         }
         Err(e) => println!("Failed to parse JSON: {}", e),
     }
+
+    match decode::<Person>(json_str) {
+        Ok(person) => println!(
+            "Decoded person: {} ({} years old, student: {}, courses: {:?})",
+            person.name, person.age, person.is_student, person.courses
+        ),
+        Err(e) => println!("Failed to decode person: {}", e),
+    }
 }
 
 #[cfg(test)]
@@ -121,8 +842,31 @@ mod tests {
 
     #[test]
     fn test_parse_number() {
-        assert_eq!(JsonValue::parse("42").unwrap(), JsonValue::Number(42.0));
+        assert_eq!(JsonValue::parse("42").unwrap(), JsonValue::I64(42));
+        assert_eq!(JsonValue::parse("-42").unwrap(), JsonValue::I64(-42));
+        assert_eq!(JsonValue::parse("2.5").unwrap(), JsonValue::F64(2.5));
+        assert_eq!(JsonValue::parse("1e3").unwrap(), JsonValue::F64(1000.0));
         assert!(JsonValue::parse("42.abc").is_err());
+        assert!(JsonValue::parse("1e309").is_err());
+    }
+
+    #[test]
+    fn test_parse_number_large_integer_keeps_precision() {
+        assert_eq!(
+            JsonValue::parse("9223372036854775807").unwrap(),
+            JsonValue::I64(i64::MAX)
+        );
+        assert_eq!(
+            JsonValue::parse("18446744073709551615").unwrap(),
+            JsonValue::U64(u64::MAX)
+        );
+    }
+
+    #[test]
+    fn test_stringify_integer_has_no_trailing_decimal() {
+        assert_eq!(JsonValue::I64(30).to_string(), "30");
+        assert_eq!(JsonValue::U64(30).to_string(), "30");
+        assert_eq!(JsonValue::F64(3.5).to_string(), "3.5");
     }
 
     #[test]
@@ -152,7 +896,7 @@ mod tests {
 
         let mut expected = HashMap::new();
         expected.insert("key1".to_string(), JsonValue::String("value1".to_string()));
-        expected.insert("key2".to_string(), JsonValue::Number(10.0));
+        expected.insert("key2".to_string(), JsonValue::I64(10));
         expected.insert("key3".to_string(), JsonValue::Boolean(false));
 
         assert_eq!(
@@ -161,11 +905,168 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_parse_array() {
+        assert_eq!(
+            JsonValue::parse("[1, 2, 3]").unwrap(),
+            JsonValue::Array(vec![
+                JsonValue::I64(1),
+                JsonValue::I64(2),
+                JsonValue::I64(3),
+            ])
+        );
+
+        assert_eq!(JsonValue::parse("[]").unwrap(), JsonValue::Array(vec![]));
+    }
+
+    #[test]
+    fn test_parse_nested_array() {
+        let json_str = r#"["math", {"id": 1}]"#;
+        let mut course = HashMap::new();
+        course.insert("id".to_string(), JsonValue::I64(1));
+
+        assert_eq!(
+            JsonValue::parse(json_str).unwrap(),
+            JsonValue::Array(vec![
+                JsonValue::String("math".to_string()),
+                JsonValue::Object(course),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_parse_string_escapes() {
+        assert_eq!(
+            JsonValue::parse(r#""line\nbreak""#).unwrap(),
+            JsonValue::String("line\nbreak".to_string())
+        );
+        assert_eq!(
+            JsonValue::parse(r#""quote\"and\\slash""#).unwrap(),
+            JsonValue::String("quote\"and\\slash".to_string())
+        );
+        assert_eq!(
+            JsonValue::parse("\"\\u00e9\"").unwrap(),
+            JsonValue::String("é".to_string())
+        );
+        assert_eq!(
+            JsonValue::parse("\"\\ud83d\\ude00\"").unwrap(),
+            JsonValue::String("😀".to_string())
+        );
+        assert!(JsonValue::parse("\"\\ud83d\"").is_err());
+    }
+
+    #[test]
+    fn test_stringify_escapes_round_trip() {
+        let json = JsonValue::String("line\nbreak \"quoted\" café 😀".to_string());
+        let stringified = json.to_string();
+        assert_eq!(JsonValue::parse(&stringified).unwrap(), json);
+    }
+
+    #[test]
+    fn test_parse_nested_object() {
+        let json_str = r#"{"a": {"b": 1}, "c": "x,y"}"#;
+
+        let mut inner = HashMap::new();
+        inner.insert("b".to_string(), JsonValue::I64(1));
+
+        let mut expected = HashMap::new();
+        expected.insert("a".to_string(), JsonValue::Object(inner));
+        expected.insert("c".to_string(), JsonValue::String("x,y".to_string()));
+
+        assert_eq!(JsonValue::parse(json_str).unwrap(), JsonValue::Object(expected));
+    }
+
+    #[test]
+    fn test_streaming_parser_events() {
+        let mut parser = Parser::new(r#"{"name": "Jo", "scores": [1, 2]}"#);
+        let events: Vec<JsonEvent> = parser.by_ref().collect();
+
+        assert_eq!(
+            events,
+            vec![
+                JsonEvent::ObjectStart,
+                JsonEvent::StringValue("Jo".to_string()),
+                JsonEvent::ArrayStart,
+                JsonEvent::I64Value(1),
+                JsonEvent::I64Value(2),
+                JsonEvent::ArrayEnd,
+                JsonEvent::ObjectEnd,
+            ]
+        );
+    }
+
+    #[test]
+    fn test_streaming_parser_stack_tracks_current_path() {
+        let mut parser = Parser::new(r#"{"courses": ["math", {"id": 1}]}"#);
+
+        loop {
+            match parser.next() {
+                Some(JsonEvent::I64Value(1)) => {
+                    assert_eq!(
+                        parser.stack(),
+                        &[
+                            StackElement::Key("courses".to_string()),
+                            StackElement::Index(1),
+                            StackElement::Key("id".to_string()),
+                        ]
+                    );
+                    return;
+                }
+                Some(_) => continue,
+                None => panic!("expected to find the nested id field"),
+            }
+        }
+    }
+
+    #[test]
+    fn test_parse_error_reports_line_and_column() {
+        let json_str = "{\n    \"a\": 1,\n    \"b\" 2\n}";
+        let err = JsonValue::parse(json_str).unwrap_err();
+
+        assert_eq!(err.code, ErrorCode::ExpectedColon);
+        assert_eq!(err.line, 3);
+        assert_eq!(err.column, 9);
+        assert_eq!(err.to_string(), "expected ':' at line 3 column 9");
+    }
+
+    #[derive(Debug, PartialEq)]
+    struct Settings {
+        opt: Option<usize>,
+    }
+
+    impl Decodable for Settings {
+        fn decode(value: &JsonValue) -> Result<Self, DecodeError> {
+            let d = Decoder::new(value);
+            Ok(Settings { opt: d.read("opt")? })
+        }
+    }
+
+    #[test]
+    fn test_decode_missing_field_defaults_option_to_none() {
+        let settings: Settings = decode("{}").unwrap();
+        assert_eq!(settings, Settings { opt: None });
+    }
+
+    #[test]
+    fn test_decode_present_field_decodes_some() {
+        let settings: Settings = decode(r#"{"opt": 10}"#).unwrap();
+        assert_eq!(settings, Settings { opt: Some(10) });
+    }
+
+    #[test]
+    fn test_decode_wrong_shape_is_a_typed_error() {
+        let err = <usize as Decodable>::decode(&JsonValue::Array(vec![])).unwrap_err();
+        assert_eq!(
+            err,
+            DecodeError::ExpectedError("Number".to_string(), "[]".to_string())
+        );
+    }
+
     #[test]
     fn test_stringify() {
         let mut map = HashMap::new();
         map.insert("key1".to_string(), JsonValue::String("value1".to_string()));
-        map.insert("key2".to_string(), JsonValue::Number(42.0));
+        map.insert("key2".to_string(), JsonValue::I64(42));
         map.insert("key3".to_string(), JsonValue::Boolean(true));
         map.insert("key4".to_string(), JsonValue::Null);
 
@@ -177,4 +1078,30 @@ mod tests {
             r#"{"key1":"value1","key2":42,"key3":true,"key4":null}"#
         );
     }
+
+    #[test]
+    fn test_to_string_pretty_nests_and_indents() {
+        let mut inner = HashMap::new();
+        inner.insert("b".to_string(), JsonValue::I64(1));
+
+        let mut outer = HashMap::new();
+        outer.insert("a".to_string(), JsonValue::Object(inner));
+        outer.insert(
+            "c".to_string(),
+            JsonValue::Array(vec![JsonValue::I64(1), JsonValue::I64(2)]),
+        );
+
+        let pretty = JsonValue::Object(outer).to_string_pretty(2);
+
+        assert_eq!(
+            pretty,
+            "{\n  \"a\": {\n    \"b\": 1\n  },\n  \"c\": [\n    1,\n    2\n  ]\n}"
+        );
+    }
+
+    #[test]
+    fn test_to_string_pretty_empty_containers_stay_compact() {
+        assert_eq!(JsonValue::Array(vec![]).to_string_pretty(2), "[]");
+        assert_eq!(JsonValue::Object(HashMap::new()).to_string_pretty(2), "{}");
+    }
 }